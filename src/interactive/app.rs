@@ -0,0 +1,179 @@
+use crate::interactive::ui::{InFlightPublish, PayloadView, PublishAck};
+use crate::mqtt_history::History;
+use std::collections::HashSet;
+use std::error::Error;
+use std::sync::{Arc, Mutex};
+use tui_tree_widget::TreeState;
+
+/// Sends outbound packets for the publish workbench: the PUBLISH itself,
+/// and the PUBREL that completes the QoS 2 handshake once a PUBREC comes
+/// back.
+pub trait MqttSender {
+    /// Send a PUBLISH and report the packet id the broker assigned it, so
+    /// the QoS 1/2 ack handshake can be tracked against it.
+    fn publish(
+        &mut self,
+        topic: &str,
+        qos: u8,
+        retain: bool,
+        payload: Vec<u8>,
+    ) -> Result<u16, Box<dyn Error>>;
+
+    /// Send the PUBREL for `packet_id` once its PUBREC has arrived.
+    fn pubrel(&mut self, packet_id: u16) -> Result<(), Box<dyn Error>>;
+}
+
+/// An inbound acknowledgement packet from the broker, as opposed to
+/// `PublishAck` which tracks what our side is currently waiting on.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum InboundAck {
+    Puback,
+    Pubrec,
+    Pubcomp,
+}
+
+/// All mutable state the interactive TUI renders from and reacts to key
+/// events against.
+pub struct App {
+    pub host: String,
+    pub port: u16,
+    pub subscribe_topic: String,
+
+    pub history: Arc<Mutex<History>>,
+    pub topic_overview_state: TreeState,
+    pub opened_topics: HashSet<String>,
+    pub selected_topic: Option<String>,
+
+    pub filter: Option<String>,
+    pub filter_input_active: bool,
+
+    pub payload_view: PayloadView,
+
+    pub client: Box<dyn MqttSender>,
+    pub publish_mode: bool,
+    pub publish_buffer: String,
+    pub publish_qos: u8,
+    pub publish_retain: bool,
+    pub in_flight_publish: Option<InFlightPublish>,
+
+    /// The most recent error from a publish or ack-handshake send, shown
+    /// in the info header until the next successful action clears it.
+    pub last_error: Option<String>,
+}
+
+impl App {
+    pub fn new(
+        host: String,
+        port: u16,
+        subscribe_topic: String,
+        client: Box<dyn MqttSender>,
+    ) -> Self {
+        Self {
+            host,
+            port,
+            subscribe_topic,
+            history: Arc::new(Mutex::new(History::new())),
+            topic_overview_state: TreeState::default(),
+            opened_topics: HashSet::new(),
+            selected_topic: None,
+            filter: None,
+            filter_input_active: false,
+            payload_view: PayloadView::default(),
+            client,
+            publish_mode: false,
+            publish_buffer: String::new(),
+            publish_qos: 0,
+            publish_retain: false,
+            in_flight_publish: None,
+            last_error: None,
+        }
+    }
+
+    /// Enter publish mode for the selected topic, pre-filling the buffer
+    /// with its last payload so the user can tweak-and-republish.
+    pub fn enter_publish_mode(&mut self, history: &History) {
+        let Some(topic) = &self.selected_topic else {
+            return;
+        };
+        self.publish_buffer = history
+            .get(topic)
+            .and_then(|entries| entries.last())
+            .map(|entry| crate::format::payload_as_utf8(entry.packet.payload.clone()))
+            .unwrap_or_default();
+        self.publish_mode = true;
+    }
+
+    pub fn cancel_publish(&mut self) {
+        self.publish_mode = false;
+    }
+
+    /// Send `publish_buffer` to the selected topic and start tracking the
+    /// QoS 1/2 ack handshake (QoS 0 has nothing to wait for).
+    pub fn send_publish(&mut self) -> Result<(), Box<dyn Error>> {
+        let Some(topic) = self.selected_topic.clone() else {
+            return Ok(());
+        };
+
+        let packet_id = self.client.publish(
+            &topic,
+            self.publish_qos,
+            self.publish_retain,
+            self.publish_buffer.clone().into_bytes(),
+        )?;
+
+        self.in_flight_publish = match self.publish_qos {
+            1 => Some(InFlightPublish {
+                packet_id,
+                ack: PublishAck::AwaitingPuback,
+            }),
+            2 => Some(InFlightPublish {
+                packet_id,
+                ack: PublishAck::AwaitingPubrec,
+            }),
+            _ => None,
+        };
+        self.publish_mode = false;
+        self.last_error = None;
+        Ok(())
+    }
+
+    /// Advance the in-flight publish's ack state machine in response to an
+    /// inbound PUBACK/PUBREC/PUBCOMP for `packet_id`, sending the PUBREL
+    /// that completes the QoS 2 handshake once the PUBREC arrives.
+    pub fn advance_publish_ack(
+        &mut self,
+        packet_id: u16,
+        received: InboundAck,
+    ) -> Result<(), Box<dyn Error>> {
+        let Some(current) = self
+            .in_flight_publish
+            .filter(|publish| publish.packet_id == packet_id)
+            .map(|publish| publish.ack)
+        else {
+            return Ok(());
+        };
+
+        let next = match (current, received) {
+            (PublishAck::AwaitingPuback, InboundAck::Puback) => Some(PublishAck::Done),
+            (PublishAck::AwaitingPubrec, InboundAck::Pubrec) => {
+                self.client.pubrel(packet_id)?;
+                Some(PublishAck::AwaitingPubcomp)
+            }
+            (PublishAck::AwaitingPubcomp, InboundAck::Pubcomp) => Some(PublishAck::Done),
+            _ => None,
+        };
+
+        match next {
+            Some(PublishAck::Done) => self.in_flight_publish = None,
+            Some(ack) => {
+                if let Some(publish) = &mut self.in_flight_publish {
+                    publish.ack = ack;
+                }
+            }
+            None => {}
+        }
+
+        self.last_error = None;
+        Ok(())
+    }
+}
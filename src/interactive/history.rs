@@ -0,0 +1,47 @@
+use crate::format;
+use crate::mqtt_history::HistoryEntry;
+use tui::backend::Backend;
+use tui::layout::Rect;
+use tui::widgets::{Block, Borders, List, ListItem};
+use tui::Frame;
+
+/// Render the full message history for the selected topic, most recent
+/// message last, one line per message with its QoS and retain state.
+pub fn draw<B>(f: &mut Frame<B>, area: Rect, history: &[HistoryEntry])
+where
+    B: Backend,
+{
+    let items = history
+        .iter()
+        .map(|entry| ListItem::new(format_entry(entry)))
+        .collect::<Vec<_>>();
+
+    let widget = List::new(items).block(Block::default().borders(Borders::ALL).title("History"));
+    f.render_widget(widget, area);
+}
+
+fn format_entry(entry: &HistoryEntry) -> String {
+    let retained = if entry.packet.retain { " R" } else { "" };
+    format!(
+        "Q{}{} {}",
+        entry.packet.qos,
+        retained,
+        format::payload_as_utf8(entry.packet.payload.clone())
+    )
+}
+
+#[test]
+fn format_entry_marks_retained_messages() {
+    use crate::mqtt_history::PublishProperties;
+
+    let entry = HistoryEntry::from_publish(b"hi".to_vec(), 1, true, PublishProperties::default());
+    assert_eq!(format_entry(&entry), "Q1 R hi");
+}
+
+#[test]
+fn format_entry_omits_marker_when_not_retained() {
+    use crate::mqtt_history::PublishProperties;
+
+    let entry = HistoryEntry::from_publish(b"hi".to_vec(), 0, false, PublishProperties::default());
+    assert_eq!(format_entry(&entry), "Q0 hi");
+}
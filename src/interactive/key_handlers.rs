@@ -0,0 +1,94 @@
+use crate::interactive::app::App;
+use crossterm::event::KeyCode;
+
+/// Dispatch a key event to the currently active input mode.
+///
+/// Returns `true` if the key was consumed and a redraw is needed.
+pub fn on_key(app: &mut App, key: KeyCode) -> bool {
+    if app.publish_mode {
+        return on_publish_key(app, key);
+    }
+    if app.filter_input_active {
+        return on_filter_key(app, key);
+    }
+
+    match key {
+        KeyCode::Char('v') => {
+            app.payload_view = app.payload_view.next();
+            true
+        }
+        KeyCode::Char('/') => {
+            app.filter_input_active = true;
+            true
+        }
+        KeyCode::Char('p') if app.selected_topic.is_some() => {
+            let history = app.history.clone();
+            let history = history.lock().expect("mqtt history lock poisoned");
+            app.enter_publish_mode(&history);
+            true
+        }
+        _ => false,
+    }
+}
+
+/// Key handling while composing an outbound publish (`app.publish_mode`).
+fn on_publish_key(app: &mut App, key: KeyCode) -> bool {
+    match key {
+        KeyCode::Esc => {
+            app.cancel_publish();
+            true
+        }
+        KeyCode::Enter => {
+            if let Err(err) = app.send_publish() {
+                app.last_error = Some(err.to_string());
+            }
+            true
+        }
+        KeyCode::Backspace => {
+            app.publish_buffer.pop();
+            true
+        }
+        KeyCode::Tab => {
+            app.publish_qos = (app.publish_qos + 1) % 3;
+            true
+        }
+        KeyCode::F(2) => {
+            app.publish_retain = !app.publish_retain;
+            true
+        }
+        KeyCode::Char(c) => {
+            app.publish_buffer.push(c);
+            true
+        }
+        _ => false,
+    }
+}
+
+/// Key handling while editing the topic filter (`app.filter_input_active`).
+fn on_filter_key(app: &mut App, key: KeyCode) -> bool {
+    match key {
+        KeyCode::Esc => {
+            app.filter_input_active = false;
+            app.filter = None;
+            true
+        }
+        KeyCode::Enter => {
+            app.filter_input_active = false;
+            true
+        }
+        KeyCode::Backspace => {
+            if let Some(filter) = &mut app.filter {
+                filter.pop();
+                if filter.is_empty() {
+                    app.filter = None;
+                }
+            }
+            true
+        }
+        KeyCode::Char(c) => {
+            app.filter.get_or_insert_with(String::new).push(c);
+            true
+        }
+        _ => false,
+    }
+}
@@ -10,11 +10,22 @@ pub struct TopicTreeEntry {
     pub leaf: String,
     pub messages: usize,
     pub last_payload: Option<Vec<u8>>,
+    pub last_qos: Option<u8>,
+    pub retained: bool,
     pub topics_below: usize,
     pub messages_below: usize,
     pub entries_below: Vec<TopicTreeEntry>,
 }
 
+/// Whether any of `entries` or anything below them was last published with the
+/// retain flag set, used to hint at stuck retained messages under a
+/// collapsed parent.
+fn has_retained_below(entries: &[TopicTreeEntry]) -> bool {
+    entries
+        .iter()
+        .any(|entry| entry.retained || has_retained_below(&entry.entries_below))
+}
+
 impl<'a> From<&'a TopicTreeEntry> for TreeItem<'a> {
     fn from(entry: &'a TopicTreeEntry) -> Self {
         let children = entry
@@ -23,16 +34,25 @@ impl<'a> From<&'a TopicTreeEntry> for TreeItem<'a> {
             .map(std::convert::Into::into)
             .collect::<Vec<_>>();
 
-        let meta = entry.last_payload.as_ref().map_or_else(
+        let mut meta = entry.last_payload.as_ref().map_or_else(
             || {
                 format!(
                     "({} topics, {} messages)",
                     entry.topics_below, entry.messages_below
                 )
             },
-            |payload| format!("= {}", crate::format::payload_as_utf8(payload.clone())),
+            |payload| {
+                let qos = entry
+                    .last_qos
+                    .map_or_else(String::new, |qos| format!(" Q{}", qos));
+                format!("= {}{}", crate::format::payload_as_utf8(payload.clone()), qos)
+            },
         );
 
+        if entry.retained || has_retained_below(&entry.entries_below) {
+            meta.push_str(" ⟳");
+        }
+
         let text = vec![Spans::from(vec![
             Span::styled(&entry.leaf, Style::default().add_modifier(Modifier::BOLD)),
             Span::raw(" "),
@@ -53,6 +73,8 @@ impl TopicTreeEntry {
                 leaf: "foo".into(),
                 messages: 0,
                 last_payload: None,
+                last_qos: None,
+                retained: false,
                 topics_below: 2,
                 messages_below: 2,
                 entries_below: vec![
@@ -61,6 +83,8 @@ impl TopicTreeEntry {
                         leaf: "bar".into(),
                         messages: 1,
                         last_payload: Some("D".into()),
+                        last_qos: Some(0),
+                        retained: false,
                         entries_below: vec![],
                         topics_below: 0,
                         messages_below: 0,
@@ -70,6 +94,8 @@ impl TopicTreeEntry {
                         leaf: "test".into(),
                         messages: 1,
                         last_payload: Some("B".into()),
+                        last_qos: Some(1),
+                        retained: true,
                         entries_below: vec![],
                         topics_below: 0,
                         messages_below: 0,
@@ -81,6 +107,8 @@ impl TopicTreeEntry {
                 leaf: "test".into(),
                 messages: 2,
                 last_payload: Some("C".into()),
+                last_qos: Some(2),
+                retained: false,
                 topics_below: 0,
                 messages_below: 0,
                 entries_below: vec![],
@@ -89,6 +117,83 @@ impl TopicTreeEntry {
     }
 }
 
+/// Case-insensitive substring match, or `*`-glob match when `filter`
+/// contains a `*`, against the full topic path.
+fn topic_matches(topic: &str, filter: &str) -> bool {
+    let topic = topic.to_lowercase();
+    let filter = filter.to_lowercase();
+    if filter.contains('*') {
+        glob_match(filter.as_bytes(), topic.as_bytes())
+    } else {
+        topic.contains(&filter)
+    }
+}
+
+/// Minimal `*`-only glob matcher, e.g. `sensors/*/temp`.
+fn glob_match(pattern: &[u8], text: &[u8]) -> bool {
+    match pattern.first() {
+        None => text.is_empty(),
+        Some(b'*') => {
+            glob_match(&pattern[1..], text)
+                || (!text.is_empty() && glob_match(pattern, &text[1..]))
+        }
+        Some(&p) => text.first().is_some_and(|&t| t == p) && glob_match(&pattern[1..], &text[1..]),
+    }
+}
+
+/// Prune `entries` down to nodes that match `filter`, keeping every
+/// ancestor of a match visible (and dropping branches with no match at
+/// all) so `get_visible`/`draw_overview` can render the result as-is.
+pub fn filter_tree(entries: &[TopicTreeEntry], filter: &str) -> Vec<TopicTreeEntry> {
+    entries
+        .iter()
+        .filter_map(|entry| filter_entry(entry, filter))
+        .collect()
+}
+
+fn filter_entry(entry: &TopicTreeEntry, filter: &str) -> Option<TopicTreeEntry> {
+    let entries_below = filter_tree(&entry.entries_below, filter);
+
+    if topic_matches(&entry.topic, filter) || !entries_below.is_empty() {
+        Some(TopicTreeEntry {
+            entries_below,
+            ..entry.clone()
+        })
+    } else {
+        None
+    }
+}
+
+/// Every topic present anywhere in `entries`, used to auto-open the
+/// ancestors of a match while a filter is active.
+pub fn all_topics(entries: &[TopicTreeEntry]) -> Vec<String> {
+    entries
+        .iter()
+        .flat_map(|entry| {
+            let mut topics = vec![entry.topic.clone()];
+            topics.extend(all_topics(&entry.entries_below));
+            topics
+        })
+        .collect()
+}
+
+/// Count of real, published-to topics below (and including) `entries`
+/// whose own name satisfies `filter`, used for the `Topics (matched/total)`
+/// title. Requiring `messages > 0` keeps this in step with `topic_amount`
+/// (`topics.len()`), which also only counts real topics; without it, a
+/// virtual grouping node (e.g. `home` when only `home/kitchen/temp` was
+/// ever published to) would match the filter text on its own path and get
+/// double-counted alongside the real leaf it groups.
+pub fn count_matching(entries: &[TopicTreeEntry], filter: &str) -> usize {
+    entries
+        .iter()
+        .map(|entry| {
+            usize::from(entry.messages > 0 && topic_matches(&entry.topic, filter))
+                + count_matching(&entry.entries_below, filter)
+        })
+        .sum()
+}
+
 pub fn get_visible<'a, I>(opened: &HashSet<String>, entries: I) -> Vec<&'a TopicTreeEntry>
 where
     I: IntoIterator<Item = &'a TopicTreeEntry>,
@@ -122,4 +227,117 @@ fn visible_topics_some_open_works() {
     let visible = get_visible(&opened, &topics);
     let visible = visible.iter().map(|o| o.topic.clone()).collect::<Vec<_>>();
     assert_eq!(visible, ["foo", "foo/bar", "foo/test", "test"]);
+}
+
+#[test]
+fn retained_below_detects_retained_child() {
+    let topics = TopicTreeEntry::examples();
+    assert!(has_retained_below(&topics[0].entries_below));
+    assert!(!has_retained_below(&topics[1].entries_below));
+}
+
+#[test]
+fn filter_tree_keeps_matches_and_their_ancestors() {
+    let topics = TopicTreeEntry::examples();
+    let filtered = filter_tree(&topics, "bar");
+    let topics = filtered.iter().map(|o| o.topic.clone()).collect::<Vec<_>>();
+    assert_eq!(topics, ["foo"]);
+    assert_eq!(filtered[0].entries_below.len(), 1);
+    assert_eq!(filtered[0].entries_below[0].topic, "foo/bar");
+}
+
+#[test]
+fn filter_tree_is_case_insensitive() {
+    let topics = TopicTreeEntry::examples();
+    let filtered = filter_tree(&topics, "BAR");
+    assert_eq!(filtered[0].entries_below[0].topic, "foo/bar");
+}
+
+#[test]
+fn filter_tree_supports_glob_wildcards() {
+    let topics = TopicTreeEntry::examples();
+    let filtered = filter_tree(&topics, "foo/*");
+    let topics = filtered[0]
+        .entries_below
+        .iter()
+        .map(|o| o.topic.clone())
+        .collect::<Vec<_>>();
+    assert_eq!(topics, ["foo/bar", "foo/test"]);
+}
+
+#[test]
+fn filter_tree_drops_non_matching_branches() {
+    let topics = TopicTreeEntry::examples();
+    let filtered = filter_tree(&topics, "nope");
+    assert!(filtered.is_empty());
+}
+
+#[test]
+fn count_matching_ignores_ancestors_kept_only_for_structure() {
+    // "foo" was itself published to (messages > 0) as well as "foo/bar",
+    // but only "foo/bar" matches the filter text; "foo" must not be
+    // counted just because it also happens to carry messages.
+    let topics = vec![TopicTreeEntry {
+        topic: "foo".into(),
+        leaf: "foo".into(),
+        messages: 1,
+        last_payload: Some("D".into()),
+        last_qos: None,
+        retained: false,
+        topics_below: 1,
+        messages_below: 1,
+        entries_below: vec![TopicTreeEntry {
+            topic: "foo/bar".into(),
+            leaf: "bar".into(),
+            messages: 1,
+            last_payload: Some("D".into()),
+            last_qos: None,
+            retained: false,
+            topics_below: 0,
+            messages_below: 0,
+            entries_below: vec![],
+        }],
+    }];
+
+    assert_eq!(count_matching(&topics, "bar"), 1);
+}
+
+#[test]
+fn count_matching_ignores_virtual_grouping_nodes() {
+    // Only "home/kitchen/temp" was ever published to; "home" and
+    // "home/kitchen" are virtual nodes (messages == 0) that exist only to
+    // group it. Filtering on "home" must count just the one real topic.
+    let topics = vec![TopicTreeEntry {
+        topic: "home".into(),
+        leaf: "home".into(),
+        messages: 0,
+        last_payload: None,
+        last_qos: None,
+        retained: false,
+        topics_below: 1,
+        messages_below: 1,
+        entries_below: vec![TopicTreeEntry {
+            topic: "home/kitchen".into(),
+            leaf: "kitchen".into(),
+            messages: 0,
+            last_payload: None,
+            last_qos: None,
+            retained: false,
+            topics_below: 1,
+            messages_below: 1,
+            entries_below: vec![TopicTreeEntry {
+                topic: "home/kitchen/temp".into(),
+                leaf: "temp".into(),
+                messages: 1,
+                last_payload: Some("21".into()),
+                last_qos: None,
+                retained: false,
+                topics_below: 0,
+                messages_below: 0,
+                entries_below: vec![],
+            }],
+        }],
+    }];
+
+    assert_eq!(count_matching(&topics, "home"), 1);
 }
\ No newline at end of file
@@ -37,6 +37,27 @@ where
         text.push(Spans::from(format!("Selected Topic: {}", topic)));
     }
 
+    if app.filter_input_active {
+        text.push(Spans::from(format!(
+            "Filter: {}_",
+            app.filter.as_deref().unwrap_or_default()
+        )));
+    } else if let Some(filter) = &app.filter {
+        text.push(Spans::from(format!("Filter: {}", filter)));
+    }
+
+    if let Some(publish) = &app.in_flight_publish {
+        text.push(Spans::from(format!(
+            "Publish: packet id {} ({})",
+            publish.packet_id,
+            publish.ack.label()
+        )));
+    }
+
+    if let Some(error) = &app.last_error {
+        text.push(Spans::from(format!("Error: {}", error)));
+    }
+
     let title = format!("MQTT TUI {}", env!("CARGO_PKG_VERSION"));
     let block = Block::default().borders(Borders::ALL).title(title);
     let paragraph = Paragraph::new(text).block(block).wrap(Wrap { trim: true });
@@ -55,11 +76,22 @@ where
     let topics = mqtt_history::history_to_tmlp(history.iter());
     let tree_items = topic_view::get_tmlp_as_tree(&topics);
 
-    // Move opened_topics over to TreeState
+    let filtered_tree_items = app
+        .filter
+        .as_ref()
+        .map_or_else(|| tree_items.clone(), |filter| topic_view::filter_tree(&tree_items, filter));
+
+    // Move opened_topics over to TreeState, auto-opening every ancestor of a
+    // match while a filter is active so matches stay reachable.
     app.topic_overview_state.close_all();
-    for topic in &app.opened_topics {
+    let opened_topics: Vec<String> = if app.filter.is_some() {
+        topic_view::all_topics(&filtered_tree_items)
+    } else {
+        app.opened_topics.iter().cloned().collect()
+    };
+    for topic in &opened_topics {
         app.topic_overview_state
-            .open(topic_view::get_identifier_of_topic(&tree_items, topic).unwrap_or_default());
+            .open(topic_view::get_identifier_of_topic(&filtered_tree_items, topic).unwrap_or_default());
     }
 
     // Ensure selected topic is selected index
@@ -67,7 +99,7 @@ where
         app.selected_topic
             .as_ref()
             .and_then(|selected_topic| {
-                topic_view::get_identifier_of_topic(&tree_items, selected_topic)
+                topic_view::get_identifier_of_topic(&filtered_tree_items, selected_topic)
             })
             .unwrap_or_default(),
     );
@@ -83,18 +115,27 @@ where
             .direction(Direction::Horizontal)
             .split(area);
 
-        draw_details(f, chunks[1], topic_history);
+        if app.publish_mode {
+            draw_publish(f, chunks[1], app);
+        } else {
+            draw_details(f, chunks[1], app, topic_history);
+        }
 
         chunks[0]
     } else {
         area
     };
 
+    let matched_amount = app.filter.as_ref().map_or(topics.len(), |filter| {
+        topic_view::count_matching(&tree_items, filter)
+    });
+
     draw_overview(
         f,
         overview_area,
+        matched_amount,
         topics.len(),
-        &tree_items,
+        &filtered_tree_items,
         &mut app.topic_overview_state,
     );
     Ok(())
@@ -103,13 +144,18 @@ where
 fn draw_overview<B>(
     f: &mut Frame<B>,
     area: Rect,
+    matched_amount: usize,
     topic_amount: usize,
     tree_items: &[TopicTreeEntry],
     state: &mut TreeState,
 ) where
     B: Backend,
 {
-    let title = format!("Topics ({})", topic_amount);
+    let title = if matched_amount == topic_amount {
+        format!("Topics ({})", topic_amount)
+    } else {
+        format!("Topics ({}/{})", matched_amount, topic_amount)
+    };
 
     let tree_items = topic_view::tree_items_from_tmlp_tree(&tree_items);
 
@@ -119,25 +165,28 @@ fn draw_overview<B>(
     f.render_stateful_widget(widget, area, state);
 }
 
-fn draw_details<B>(f: &mut Frame<B>, area: Rect, topic_history: &[HistoryEntry])
+fn draw_details<B>(f: &mut Frame<B>, area: Rect, app: &App, topic_history: &[HistoryEntry])
 where
     B: Backend,
 {
     let last = topic_history.last().unwrap();
     let payload_length = last.packet.payload.len();
-    let payload_json = format::payload_as_json(last.packet.payload.to_vec());
-
-    let payload = payload_json.map_or(
-        format::payload_as_utf8(last.packet.payload.to_vec()),
-        |payload| json::stringify_pretty(payload, 2),
-    );
+    let payload = render_payload(last, app.payload_view);
     let lines = payload.matches('\n').count().saturating_add(1);
 
+    let properties_height = properties_lines(last).len();
+
     let chunks = Layout::default()
         .constraints(
             [
                 #[allow(clippy::cast_possible_truncation)]
                 Constraint::Length(min(area.height as usize / 3, 2 + lines) as u16),
+                #[allow(clippy::cast_possible_truncation)]
+                Constraint::Length(if properties_height == 0 {
+                    0
+                } else {
+                    2 + properties_height as u16
+                }),
                 Constraint::Min(16),
             ]
             .as_ref(),
@@ -145,7 +194,211 @@ where
         .split(area);
 
     draw_payload(f, chunks[0], payload_length, &payload);
-    history::draw(f, chunks[1], topic_history);
+    draw_properties(f, chunks[1], last);
+    history::draw(f, chunks[2], topic_history);
+}
+
+/// Build one formatted line per present MQTT v5 property, in a fixed order
+/// (user properties last, in the order stored), omitting anything the broker
+/// didn't send.
+fn properties_lines(entry: &HistoryEntry) -> Vec<String> {
+    let props = &entry.properties;
+    let mut lines = Vec::new();
+
+    if let Some(indicator) = props.payload_format_indicator {
+        let kind = if indicator == 1 { "utf-8" } else { "bytes" };
+        lines.push(format!("payload-format = {}", kind));
+    }
+    if let Some(expiry) = props.message_expiry_interval {
+        lines.push(format!("expiry = {}s", expiry));
+    }
+    if let Some(content_type) = &props.content_type {
+        lines.push(format!("content-type = {}", content_type));
+    }
+    if let Some(response_topic) = &props.response_topic {
+        lines.push(format!("response-topic = {}", response_topic));
+    }
+    if let Some(correlation_data) = &props.correlation_data {
+        lines.push(format!(
+            "correlation-data = {}",
+            format::payload_as_utf8(correlation_data.clone())
+        ));
+    }
+    if let Some(topic_alias) = props.topic_alias {
+        lines.push(format!("topic-alias = {}", topic_alias));
+    }
+    if let Some(subscription_identifier) = props.subscription_identifier {
+        lines.push(format!("subscription-id = {}", subscription_identifier));
+    }
+    for (key, value) in &props.user_properties {
+        lines.push(format!("{} = {}", key, value));
+    }
+
+    lines
+}
+
+fn draw_properties<B>(f: &mut Frame<B>, area: Rect, entry: &HistoryEntry)
+where
+    B: Backend,
+{
+    let items = properties_lines(entry)
+        .into_iter()
+        .map(ListItem::new)
+        .collect::<Vec<_>>();
+    let widget = List::new(items)
+        .block(Block::default().borders(Borders::ALL).title("Properties"))
+        .highlight_style(Style::default().fg(Color::Black).bg(Color::LightGreen));
+    f.render_widget(widget, area);
+}
+
+/// The representation used to render a payload, cycled with a key binding.
+/// `Auto` picks JSON, UTF-8 text, or a hex dump based on the v5 properties
+/// and the bytes themselves; the other variants force one representation.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PayloadView {
+    Auto,
+    Text,
+    Hex,
+    Json,
+}
+
+impl Default for PayloadView {
+    fn default() -> Self {
+        Self::Auto
+    }
+}
+
+impl PayloadView {
+    #[must_use]
+    pub const fn next(self) -> Self {
+        match self {
+            Self::Auto => Self::Text,
+            Self::Text => Self::Hex,
+            Self::Hex => Self::Json,
+            Self::Json => Self::Auto,
+        }
+    }
+}
+
+fn render_payload(entry: &HistoryEntry, view: PayloadView) -> String {
+    let bytes = entry.packet.payload.to_vec();
+    let content_type_is_json = entry
+        .properties
+        .content_type
+        .as_ref()
+        .is_some_and(|content_type| content_type.to_lowercase().contains("json"));
+    let is_utf8 = entry.properties.payload_format_indicator == Some(1)
+        || std::str::from_utf8(&bytes).is_ok();
+
+    match view {
+        PayloadView::Json => render_json_or_fallback(bytes),
+        PayloadView::Text => format::payload_as_utf8(bytes),
+        PayloadView::Hex => payload_as_hex_dump(&bytes),
+        PayloadView::Auto => {
+            if content_type_is_json {
+                render_json_or_fallback(bytes)
+            } else if let Some(payload) = format::payload_as_json(bytes.clone()) {
+                json::stringify_pretty(payload, 2)
+            } else if is_utf8 {
+                format::payload_as_utf8(bytes)
+            } else {
+                payload_as_hex_dump(&bytes)
+            }
+        }
+    }
+}
+
+/// Pretty-print `bytes` as JSON, falling back to UTF-8 text if it doesn't parse.
+fn render_json_or_fallback(bytes: Vec<u8>) -> String {
+    format::payload_as_json(bytes.clone()).map_or_else(
+        || format::payload_as_utf8(bytes),
+        |payload| json::stringify_pretty(payload, 2),
+    )
+}
+
+/// Classic hex dump: one line per 16 bytes, an 8-digit offset, the bytes as
+/// two-digit hex with a gap after the eighth byte, then an ASCII gutter
+/// where non-printable bytes (`< 0x20` or `>= 0x7f`) become `.`.
+fn payload_as_hex_dump(bytes: &[u8]) -> String {
+    bytes
+        .chunks(16)
+        .enumerate()
+        .map(|(i, chunk)| {
+            let mut hex = String::new();
+            for (j, byte) in chunk.iter().enumerate() {
+                if j == 8 {
+                    hex.push(' ');
+                }
+                hex.push_str(&format!("{:02x} ", byte));
+            }
+
+            let ascii = chunk
+                .iter()
+                .map(|&byte| {
+                    if (0x20..0x7f).contains(&byte) {
+                        byte as char
+                    } else {
+                        '.'
+                    }
+                })
+                .collect::<String>();
+
+            format!("{:08x}  {:49}|{}|", i * 16, hex, ascii)
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// The QoS 1/2 handshake step an outbound publish is currently waiting on.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PublishAck {
+    AwaitingPuback,
+    AwaitingPubrec,
+    AwaitingPubrel,
+    AwaitingPubcomp,
+    Done,
+}
+
+impl PublishAck {
+    fn label(self) -> &'static str {
+        match self {
+            Self::AwaitingPuback => "awaiting PUBACK",
+            Self::AwaitingPubrec => "awaiting PUBREC",
+            Self::AwaitingPubrel => "awaiting PUBREL",
+            Self::AwaitingPubcomp => "awaiting PUBCOMP",
+            Self::Done => "acked",
+        }
+    }
+}
+
+/// State of a publish that has been sent but not yet fully acknowledged,
+/// surfaced in the info header until the handshake completes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct InFlightPublish {
+    pub packet_id: u16,
+    pub ack: PublishAck,
+}
+
+fn draw_publish<B>(f: &mut Frame<B>, area: Rect, app: &App)
+where
+    B: Backend,
+{
+    let title = format!(
+        "Publish to {} (QoS {}{})",
+        app.selected_topic.as_deref().unwrap_or_default(),
+        app.publish_qos,
+        if app.publish_retain { ", retained" } else { "" },
+    );
+
+    let items = app
+        .publish_buffer
+        .lines()
+        .map(ListItem::new)
+        .collect::<Vec<_>>();
+    let widget = List::new(items)
+        .block(Block::default().borders(Borders::ALL).title(title))
+        .highlight_style(Style::default().fg(Color::Black).bg(Color::LightGreen));
+    f.render_widget(widget, area);
 }
 
 fn draw_payload<B>(f: &mut Frame<B>, area: Rect, bytes: usize, payload: &str)
@@ -159,3 +412,114 @@ where
         .highlight_style(Style::default().fg(Color::Black).bg(Color::LightGreen));
     f.render_widget(widget, area);
 }
+
+#[test]
+fn properties_lines_omits_absent_properties() {
+    let entry = HistoryEntry::from_publish(Vec::new(), 0, false, mqtt_history::PublishProperties::default());
+    assert!(properties_lines(&entry).is_empty());
+}
+
+#[test]
+fn properties_lines_renders_every_present_property() {
+    let properties = mqtt_history::PublishProperties {
+        payload_format_indicator: Some(1),
+        message_expiry_interval: Some(60),
+        content_type: Some("application/json".into()),
+        response_topic: Some("responses/1".into()),
+        correlation_data: Some(b"abc".to_vec()),
+        topic_alias: Some(7),
+        subscription_identifier: Some(3),
+        user_properties: vec![("device".into(), "sensor-1".into())],
+    };
+    let entry = HistoryEntry::from_publish(Vec::new(), 0, false, properties);
+
+    assert_eq!(
+        properties_lines(&entry),
+        vec![
+            "payload-format = utf-8".to_string(),
+            "expiry = 60s".to_string(),
+            "content-type = application/json".to_string(),
+            "response-topic = responses/1".to_string(),
+            "correlation-data = abc".to_string(),
+            "topic-alias = 7".to_string(),
+            "subscription-id = 3".to_string(),
+            "device = sensor-1".to_string(),
+        ]
+    );
+}
+
+#[test]
+fn hex_dump_formats_offset_gap_and_ascii_gutter() {
+    let bytes = (0..16_u8).collect::<Vec<_>>();
+    assert_eq!(
+        payload_as_hex_dump(&bytes),
+        "00000000  00 01 02 03 04 05 06 07  08 09 0a 0b 0c 0d 0e 0f |................|"
+    );
+}
+
+#[test]
+fn hex_dump_pads_a_partial_last_line() {
+    let bytes = b"ABC".to_vec();
+    assert_eq!(
+        payload_as_hex_dump(&bytes),
+        "00000000  41 42 43                                         |ABC|"
+    );
+}
+
+#[test]
+fn hex_dump_wraps_every_sixteen_bytes() {
+    let bytes = (0..18_u8).collect::<Vec<_>>();
+    let lines = payload_as_hex_dump(&bytes);
+    let lines = lines.lines().collect::<Vec<_>>();
+    assert_eq!(lines.len(), 2);
+    assert!(lines[1].starts_with("00000010  10 11"));
+}
+
+fn entry_with(content_type: Option<&str>, indicator: Option<u8>, payload: &[u8]) -> HistoryEntry {
+    let properties = mqtt_history::PublishProperties {
+        content_type: content_type.map(String::from),
+        payload_format_indicator: indicator,
+        ..mqtt_history::PublishProperties::default()
+    };
+    HistoryEntry::from_publish(payload.to_vec(), 0, false, properties)
+}
+
+#[test]
+fn auto_view_prefers_content_type_json_even_when_the_body_fails_to_parse() {
+    let entry = entry_with(Some("application/json"), None, b"not json");
+    assert_eq!(
+        render_payload(&entry, PayloadView::Auto),
+        format::payload_as_utf8(b"not json".to_vec())
+    );
+}
+
+#[test]
+fn auto_view_falls_back_to_parsing_as_json_without_a_content_type_hint() {
+    let entry = entry_with(None, None, b"{}");
+    let expected = render_json_or_fallback(b"{}".to_vec());
+    assert_eq!(render_payload(&entry, PayloadView::Auto), expected);
+}
+
+#[test]
+fn auto_view_uses_the_utf8_indicator_over_a_hex_dump() {
+    let entry = entry_with(None, Some(1), b"plain text");
+    assert_eq!(
+        render_payload(&entry, PayloadView::Auto),
+        format::payload_as_utf8(b"plain text".to_vec())
+    );
+}
+
+#[test]
+fn auto_view_hex_dumps_payloads_with_no_json_utf8_or_indicator_hint() {
+    let bytes = vec![0xff, 0xfe, 0x00, 0x01];
+    let entry = entry_with(None, None, &bytes);
+    assert_eq!(render_payload(&entry, PayloadView::Auto), payload_as_hex_dump(&bytes));
+}
+
+#[test]
+fn payload_view_next_cycles_back_to_auto() {
+    assert_eq!(PayloadView::Auto.next(), PayloadView::Text);
+    assert_eq!(PayloadView::Text.next(), PayloadView::Hex);
+    assert_eq!(PayloadView::Hex.next(), PayloadView::Json);
+    assert_eq!(PayloadView::Json.next(), PayloadView::Auto);
+}
@@ -0,0 +1,69 @@
+use std::collections::BTreeMap;
+
+/// The MQTT v5 PUBLISH properties captured off the wire, flattened into a
+/// concrete struct (rather than kept as a library-specific `Option`) so
+/// `HistoryEntry` always has one to read, even if the broker sent none.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct PublishProperties {
+    pub payload_format_indicator: Option<u8>,
+    pub message_expiry_interval: Option<u32>,
+    pub content_type: Option<String>,
+    pub response_topic: Option<String>,
+    pub correlation_data: Option<Vec<u8>>,
+    pub topic_alias: Option<u16>,
+    pub subscription_identifier: Option<u32>,
+    pub user_properties: Vec<(String, String)>,
+}
+
+/// The raw bytes of an inbound PUBLISH, kept alongside its QoS and retain
+/// flag; its properties are stored next to it on `HistoryEntry`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Packet {
+    pub payload: Vec<u8>,
+    pub qos: u8,
+    pub retain: bool,
+}
+
+/// One message received for a topic, in arrival order.
+#[derive(Debug, Clone, PartialEq)]
+pub struct HistoryEntry {
+    pub packet: Packet,
+    pub properties: PublishProperties,
+}
+
+impl HistoryEntry {
+    /// Build a history entry from an inbound PUBLISH, capturing its QoS,
+    /// retain flag, and v5 properties (if any) so the tree, history list,
+    /// and details pane can all render them later.
+    pub fn from_publish(payload: Vec<u8>, qos: u8, retain: bool, properties: PublishProperties) -> Self {
+        Self {
+            packet: Packet { payload, qos, retain },
+            properties,
+        }
+    }
+}
+
+pub type History = BTreeMap<String, Vec<HistoryEntry>>;
+
+/// Push a newly received message onto its topic's history.
+pub fn record(history: &mut History, topic: String, entry: HistoryEntry) {
+    history.entry(topic).or_default().push(entry);
+}
+
+/// Flatten the history into `(topic, message count, last payload, last qos,
+/// retained)` tuples, the shape `topic_view::get_tmlp_as_tree` consumes to
+/// build the tree with the right badges on each node.
+pub fn history_to_tmlp<'a, I>(history: I) -> Vec<(String, usize, Option<Vec<u8>>, Option<u8>, bool)>
+where
+    I: Iterator<Item = (&'a String, &'a Vec<HistoryEntry>)>,
+{
+    history
+        .map(|(topic, entries)| {
+            let last = entries.last();
+            let last_payload = last.map(|entry| entry.packet.payload.clone());
+            let last_qos = last.map(|entry| entry.packet.qos);
+            let retained = last.is_some_and(|entry| entry.packet.retain);
+            (topic.clone(), entries.len(), last_payload, last_qos, retained)
+        })
+        .collect()
+}
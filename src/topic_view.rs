@@ -0,0 +1,88 @@
+pub use crate::interactive::topic_tree_entry::{all_topics, count_matching, filter_tree, TopicTreeEntry};
+
+/// Build the nested `TopicTreeEntry` tree from the flat `(topic, messages,
+/// last payload, last qos, retained)` tuples `mqtt_history::history_to_tmlp`
+/// produces, splitting each topic on `/` to find its place in the tree.
+pub fn get_tmlp_as_tree(topics: &[(String, usize, Option<Vec<u8>>, Option<u8>, bool)]) -> Vec<TopicTreeEntry> {
+    let mut roots: Vec<TopicTreeEntry> = Vec::new();
+    for (topic, messages, last_payload, last_qos, retained) in topics {
+        insert_topic(&mut roots, topic, "", *messages, last_payload.clone(), *last_qos, *retained);
+    }
+    roots
+}
+
+#[allow(clippy::too_many_arguments)]
+fn insert_topic(
+    entries: &mut Vec<TopicTreeEntry>,
+    full_topic: &str,
+    prefix: &str,
+    messages: usize,
+    last_payload: Option<Vec<u8>>,
+    last_qos: Option<u8>,
+    retained: bool,
+) {
+    let remainder = full_topic.strip_prefix(prefix).unwrap_or(full_topic);
+    let remainder = remainder.strip_prefix('/').unwrap_or(remainder);
+    let (leaf, rest) = remainder.split_once('/').map_or((remainder, None), |(l, r)| (l, Some(r)));
+
+    let this_topic = if prefix.is_empty() {
+        leaf.to_string()
+    } else {
+        format!("{}/{}", prefix, leaf)
+    };
+
+    let index = entries.iter().position(|entry| entry.topic == this_topic).unwrap_or_else(|| {
+        entries.push(TopicTreeEntry {
+            topic: this_topic.clone(),
+            leaf: leaf.to_string(),
+            messages: 0,
+            last_payload: None,
+            last_qos: None,
+            retained: false,
+            topics_below: 0,
+            messages_below: 0,
+            entries_below: Vec::new(),
+        });
+        entries.len() - 1
+    });
+
+    if rest.is_none() {
+        entries[index].messages = messages;
+        entries[index].last_payload = last_payload;
+        entries[index].last_qos = last_qos;
+        entries[index].retained = retained;
+    } else {
+        entries[index].messages_below += messages;
+        entries[index].topics_below = entries[index].entries_below.len().max(entries[index].topics_below);
+        insert_topic(
+            &mut entries[index].entries_below,
+            full_topic,
+            &this_topic,
+            messages,
+            last_payload,
+            last_qos,
+            retained,
+        );
+        entries[index].topics_below = entries[index].entries_below.len();
+    }
+}
+
+pub fn tree_items_from_tmlp_tree(entries: &[TopicTreeEntry]) -> Vec<tui_tree_widget::TreeItem<'_>> {
+    entries.iter().map(std::convert::Into::into).collect()
+}
+
+/// Path of child indices into `entries` leading to `topic`, the identifier
+/// `TreeState::open`/`select` expect.
+pub fn get_identifier_of_topic(entries: &[TopicTreeEntry], topic: &str) -> Option<Vec<usize>> {
+    for (index, entry) in entries.iter().enumerate() {
+        if entry.topic == topic {
+            return Some(vec![index]);
+        }
+        if let Some(mut rest) = get_identifier_of_topic(&entry.entries_below, topic) {
+            let mut path = vec![index];
+            path.append(&mut rest);
+            return Some(path);
+        }
+    }
+    None
+}